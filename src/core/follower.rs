@@ -86,7 +86,7 @@ impl<'a, T: RaftType> Follower<'a, T> {
                 Err(e) => match e {
                     RecvTimeoutError::Timeout => {
                         self.core.set_state(State::PreCandidate, set_prev_state.as_mut());
-                        self.core.current_leader = None;
+                        self.core.clear_leader();
                         info!(
                             "[Node({})] an election timeout is hit, need to transit to pre-candidate",
                             self.core.node_id
@@ -0,0 +1,89 @@
+use std::time::{Duration, Instant};
+
+/// Tracks contact with the current leader so a node can implement the
+/// check-quorum safety check: while the lease is active, vote requests from
+/// other nodes are rejected outright, which keeps an isolated or removed
+/// node from forcing a healthy leader to step down once it reconnects.
+///
+/// The lease is renewed whenever a heartbeat from the current leader is
+/// accepted and is considered active for `min_election_timeout` after the
+/// most recent contact. It is refreshed from `RaftCore::handle_heartbeat`
+/// and consulted from `RaftCore::handle_vote_request`, which rejects the
+/// request without touching `current_term` or `voted_for` unless the vote
+/// request carries an explicit leadership-transfer / force flag.
+#[derive(Debug, Default)]
+pub(crate) struct LeaderLease {
+    last_leader_contact: Option<Instant>,
+}
+
+impl LeaderLease {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renews the lease after accepting a heartbeat from the current leader.
+    #[inline]
+    pub(crate) fn renew(&mut self, now: Instant) {
+        self.last_leader_contact = Some(now);
+    }
+
+    /// Clears the lease, e.g. when the current leader is lost or replaced.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.last_leader_contact = None;
+    }
+
+    /// Returns `true` if the leader was contacted within `min_election_timeout`
+    /// of `now`, meaning a non-forced vote request should be rejected.
+    pub(crate) fn is_active(&self, now: Instant, min_election_timeout: Duration) -> bool {
+        match self.last_leader_contact {
+            Some(contact) => now.saturating_duration_since(contact) < min_election_timeout,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inactive_with_no_contact() {
+        let lease = LeaderLease::new();
+        assert!(!lease.is_active(Instant::now(), Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn active_just_under_min_election_timeout() {
+        let mut lease = LeaderLease::new();
+        let now = Instant::now();
+        lease.renew(now);
+        assert!(lease.is_active(now + Duration::from_millis(999), Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn inactive_at_min_election_timeout() {
+        let mut lease = LeaderLease::new();
+        let now = Instant::now();
+        lease.renew(now);
+        assert!(!lease.is_active(now + Duration::from_secs(1), Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn inactive_just_over_min_election_timeout() {
+        let mut lease = LeaderLease::new();
+        let now = Instant::now();
+        lease.renew(now);
+        assert!(!lease.is_active(now + Duration::from_millis(1001), Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn clear_deactivates_the_lease() {
+        let mut lease = LeaderLease::new();
+        let now = Instant::now();
+        lease.renew(now);
+        lease.clear();
+        assert!(!lease.is_active(now, Duration::from_secs(1)));
+    }
+}
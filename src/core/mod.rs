@@ -0,0 +1,287 @@
+mod follower;
+mod lease;
+mod options;
+
+pub use options::UpdateOptions;
+
+use crate::error::{Error, Result};
+use crate::msg::{HeartbeatRequest, HeartbeatResponse, Message, VoteRequest, VoteResponse};
+use crate::{Event, NodeId, RaftType};
+use crossbeam_channel::{Receiver, Sender};
+use lease::LeaderLease;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// Lifecycle state of a raft node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Startup,
+    Follower,
+    PreCandidate,
+    Candidate,
+    Leader,
+    Shutdown,
+}
+
+/// Durable raft state that must survive restarts.
+#[derive(Debug, Default)]
+pub struct HardState {
+    pub current_term: u64,
+    pub voted_for: Option<NodeId>,
+}
+
+/// Core raft state machine shared by all role loops (`Follower`, ...).
+pub struct RaftCore<T: RaftType> {
+    pub node_id: NodeId,
+    pub hard_state: HardState,
+    pub current_leader: Option<NodeId>,
+    pub next_election_timeout: Option<Instant>,
+    pub msg_rx: Receiver<Message>,
+    state: State,
+    prev_state: Option<State>,
+    min_election_timeout: Duration,
+    lease: LeaderLease,
+    leader_lease_enabled: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: RaftType> RaftCore<T> {
+    pub fn new(node_id: NodeId, min_election_timeout: Duration, msg_rx: Receiver<Message>) -> Self {
+        Self {
+            node_id,
+            hard_state: HardState::default(),
+            current_leader: None,
+            next_election_timeout: None,
+            msg_rx,
+            state: State::Startup,
+            prev_state: None,
+            min_election_timeout,
+            lease: LeaderLease::new(),
+            leader_lease_enabled: true,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn is_state(&self, state: State) -> bool {
+        self.state == state
+    }
+
+    pub fn set_state(&mut self, state: State, set_prev_state: Option<&mut bool>) {
+        if let Some(set_prev_state) = set_prev_state {
+            if *set_prev_state {
+                self.prev_state = Some(self.state);
+                *set_prev_state = false;
+            }
+        }
+        self.state = state;
+    }
+
+    #[inline]
+    pub fn prev_state(&self) -> Option<State> {
+        self.prev_state
+    }
+
+    pub fn next_election_timeout(&mut self) -> Instant {
+        let timeout = self
+            .next_election_timeout
+            .unwrap_or_else(|| Instant::now() + self.min_election_timeout);
+        self.next_election_timeout = Some(timeout);
+        timeout
+    }
+
+    pub fn spawn_event_handling_task(&self, _event: Event) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn report_metrics(&self) {}
+
+    pub fn reject_init_with_members(&self, tx: Sender<Result<()>>) {
+        let _ = tx.send(Err(Error::Rejected("node is already initialized".to_string())));
+    }
+
+    /// Forgets the current leader, e.g. on an election timeout, clearing the
+    /// check-quorum leader lease along with it so a stale lease can't keep
+    /// gating votes once the leader is no longer considered in contact.
+    pub fn clear_leader(&mut self) {
+        self.current_leader = None;
+        self.lease.clear();
+    }
+
+    /// Applies an `UpdateOptions` request, e.g. toggling the check-quorum
+    /// leader lease per group.
+    pub fn update_options(&mut self, options: UpdateOptions) {
+        if let Some(enabled) = options.enable_leader_lease {
+            self.leader_lease_enabled = enabled;
+        }
+    }
+
+    /// Handles a heartbeat from `req.leader_id`, renewing the check-quorum
+    /// leader lease whenever it is accepted.
+    pub fn handle_heartbeat(
+        &mut self,
+        req: HeartbeatRequest,
+        set_prev_state: Option<&mut bool>,
+    ) -> Result<HeartbeatResponse> {
+        if req.term < self.hard_state.current_term {
+            return Err(Error::Rejected(format!(
+                "stale heartbeat term {} < current term {}",
+                req.term, self.hard_state.current_term
+            )));
+        }
+
+        self.hard_state.current_term = req.term;
+        self.current_leader = Some(req.leader_id);
+        self.lease.renew(Instant::now());
+        self.set_state(State::Follower, set_prev_state);
+        self.next_election_timeout = None;
+
+        Ok(HeartbeatResponse { term: self.hard_state.current_term })
+    }
+
+    /// Handles a vote request. While the check-quorum leader lease is
+    /// active and the request is not a forced leadership transfer, the vote
+    /// is rejected outright without touching `current_term` or `voted_for`
+    /// — this is what keeps an isolated or removed node from disturbing a
+    /// healthy leader once it reconnects.
+    pub fn handle_vote_request(
+        &mut self,
+        req: VoteRequest,
+        set_prev_state: Option<&mut bool>,
+    ) -> Result<VoteResponse> {
+        let _ = set_prev_state;
+
+        if self.leader_lease_enabled
+            && !req.force
+            && self.current_leader.is_some()
+            && self.lease.is_active(Instant::now(), self.min_election_timeout)
+        {
+            return Ok(VoteResponse { term: self.hard_state.current_term, granted: false });
+        }
+
+        if req.term < self.hard_state.current_term {
+            return Ok(VoteResponse { term: self.hard_state.current_term, granted: false });
+        }
+
+        // A real (non-pre-vote) request carrying a higher term must be
+        // adopted before the vote itself is evaluated, per the raft safety
+        // rule that a node always steps up to any higher term it observes —
+        // otherwise a stale `voted_for` from an earlier term could reject a
+        // legitimate candidate while leaving `current_term` stuck behind it.
+        if req.term > self.hard_state.current_term && !req.pre_vote {
+            self.hard_state.current_term = req.term;
+            self.hard_state.voted_for = None;
+        }
+
+        let granted = if req.pre_vote {
+            true
+        } else if self.hard_state.voted_for.is_none() || self.hard_state.voted_for == Some(req.candidate_id) {
+            self.hard_state.voted_for = Some(req.candidate_id);
+            true
+        } else {
+            false
+        };
+
+        Ok(VoteResponse { term: self.hard_state.current_term, granted })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestType;
+    impl RaftType for TestType {}
+
+    fn core_with_leader(min_election_timeout: Duration) -> RaftCore<TestType> {
+        let (_tx, rx) = crossbeam_channel::unbounded();
+        let mut core = RaftCore::<TestType>::new(1, min_election_timeout, rx);
+        core.handle_heartbeat(HeartbeatRequest { leader_id: 2, term: 1 }, None).unwrap();
+        core
+    }
+
+    fn vote_req(force: bool) -> VoteRequest {
+        VoteRequest { candidate_id: 3, term: 2, pre_vote: true, force }
+    }
+
+    #[test]
+    fn vote_request_rejected_while_lease_active() {
+        let mut core = core_with_leader(Duration::from_secs(10));
+        let before_term = core.hard_state.current_term;
+        let before_voted_for = core.hard_state.voted_for;
+
+        let resp = core.handle_vote_request(vote_req(false), None).unwrap();
+
+        assert!(!resp.granted);
+        assert_eq!(core.hard_state.current_term, before_term);
+        assert_eq!(core.hard_state.voted_for, before_voted_for);
+    }
+
+    #[test]
+    fn vote_request_granted_once_lease_expires() {
+        // A zero-length lease is immediately expired.
+        let mut core = core_with_leader(Duration::from_millis(0));
+        std::thread::sleep(Duration::from_millis(1));
+
+        let resp = core.handle_vote_request(vote_req(false), None).unwrap();
+
+        assert!(resp.granted);
+    }
+
+    #[test]
+    fn forced_vote_request_bypasses_active_lease() {
+        let mut core = core_with_leader(Duration::from_secs(10));
+
+        let resp = core.handle_vote_request(vote_req(true), None).unwrap();
+
+        assert!(resp.granted);
+    }
+
+    #[test]
+    fn vote_request_granted_with_no_current_leader() {
+        let (_tx, rx) = crossbeam_channel::unbounded();
+        let mut core = RaftCore::<TestType>::new(1, Duration::from_secs(10), rx);
+
+        let resp = core.handle_vote_request(vote_req(false), None).unwrap();
+
+        assert!(resp.granted);
+    }
+
+    #[test]
+    fn clear_leader_releases_the_lease() {
+        let mut core = core_with_leader(Duration::from_secs(10));
+        core.clear_leader();
+
+        assert!(core.current_leader.is_none());
+
+        let resp = core.handle_vote_request(vote_req(false), None).unwrap();
+
+        assert!(resp.granted);
+    }
+
+    #[test]
+    fn higher_term_vote_request_is_adopted_despite_stale_voted_for() {
+        let (_tx, rx) = crossbeam_channel::unbounded();
+        let mut core = RaftCore::<TestType>::new(1, Duration::from_secs(10), rx);
+        core.hard_state.current_term = 1;
+        core.hard_state.voted_for = Some(9);
+
+        let resp = core
+            .handle_vote_request(VoteRequest { candidate_id: 3, term: 2, pre_vote: false, force: false }, None)
+            .unwrap();
+
+        assert!(resp.granted);
+        assert_eq!(core.hard_state.current_term, 2);
+        assert_eq!(core.hard_state.voted_for, Some(3));
+    }
+
+    #[test]
+    fn leader_lease_can_be_disabled_via_update_options() {
+        let mut core = core_with_leader(Duration::from_secs(10));
+        core.update_options(UpdateOptions { enable_leader_lease: Some(false) });
+
+        let resp = core.handle_vote_request(vote_req(false), None).unwrap();
+
+        assert!(resp.granted);
+    }
+}
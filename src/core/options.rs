@@ -0,0 +1,9 @@
+/// Options that can be changed on a running raft group via
+/// `Message::UpdateOptions`.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    /// Enables or disables the check-quorum leader lease that rejects vote
+    /// requests while a leader was recently in contact. `None` leaves the
+    /// current setting untouched.
+    pub enable_leader_lease: Option<bool>,
+}
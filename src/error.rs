@@ -0,0 +1,21 @@
+use std::fmt;
+
+/// Result type returned by raft core operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error type returned by raft core operations.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The request was rejected given the node's current state.
+    Rejected(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Rejected(reason) => write!(f, "request rejected: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate log;
+
+pub mod core;
+pub mod error;
+pub mod msg;
+pub mod task;
+
+pub use crate::core::State;
+
+/// Identifies a raft node within a group.
+pub type NodeId = u64;
+
+/// Marker trait bounding the application type driving a raft group.
+pub trait RaftType: Send + Sync + 'static {}
+
+/// Internal lifecycle events raised by the raft core.
+#[derive(Debug, Clone)]
+pub enum Event {
+    TransitToFollower { term: u64, prev_state: Option<State> },
+}
@@ -0,0 +1,64 @@
+use crate::core::UpdateOptions;
+use crate::error::{Error, Result};
+use crate::{Event, NodeId};
+use crossbeam_channel::Sender;
+use std::collections::HashSet;
+
+/// Heartbeat request sent by the leader to a follower.
+#[derive(Debug, Clone)]
+pub struct HeartbeatRequest {
+    pub leader_id: NodeId,
+    pub term: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct HeartbeatResponse {
+    pub term: u64,
+}
+
+/// Vote request sent by a candidate or pre-candidate.
+#[derive(Debug, Clone)]
+pub struct VoteRequest {
+    pub candidate_id: NodeId,
+    pub term: u64,
+    pub pre_vote: bool,
+    /// Set when the election was initiated by an explicit leadership
+    /// transfer, which must bypass the check-quorum leader lease.
+    pub force: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct VoteResponse {
+    pub term: u64,
+    pub granted: bool,
+}
+
+/// Messages routed to a raft node's main loop.
+pub enum Message {
+    Heartbeat {
+        req: HeartbeatRequest,
+        tx: Sender<Result<HeartbeatResponse>>,
+    },
+    HeartbeatResponse(Result<HeartbeatResponse>),
+    VoteRequest {
+        req: VoteRequest,
+        tx: Sender<Result<VoteResponse>>,
+    },
+    VoteResponse {
+        req: VoteResponse,
+    },
+    Initialize {
+        members: HashSet<NodeId>,
+        tx: Sender<Result<()>>,
+    },
+    UpdateOptions {
+        options: UpdateOptions,
+        tx: Sender<Result<()>>,
+    },
+    Shutdown,
+    EventHandlingResult {
+        event: Event,
+        error: Option<Error>,
+        term: u64,
+    },
+}